@@ -0,0 +1,190 @@
+use super::Formatter;
+use crate::analysers::Analyser;
+use crate::error::LaibraryError;
+use crate::types::{Namespace, PackageMetadata};
+
+/// Renders the extracted API as structured JSON: the namespace tree, with each
+/// namespace's doc comment and each symbol's name and source code, for
+/// downstream tooling that wants to consume the API programmatically rather
+/// than as rendered text.
+pub struct JsonFormatter;
+
+impl Formatter for JsonFormatter {
+    fn format(
+        &self,
+        metadata: &PackageMetadata,
+        namespaces: &[Namespace],
+        _analyser: &dyn Analyser,
+    ) -> Result<String, LaibraryError> {
+        let namespaces_json = super::root_namespaces(namespaces)
+            .into_iter()
+            .map(namespace_to_json)
+            .collect::<Vec<_>>()
+            .join(",");
+
+        Ok(format!(
+            r#"{{"name":"{name}","version":"{version}","documentation":"{documentation}","namespaces":[{namespaces_json}]}}"#,
+            name = escape(&metadata.name),
+            version = escape(&metadata.version),
+            documentation = escape(metadata.documentation.trim()),
+        ))
+    }
+}
+
+fn namespace_to_json(namespace: &Namespace) -> String {
+    let symbols_json = namespace
+        .symbols
+        .iter()
+        .map(|symbol| {
+            format!(
+                r#"{{"name":"{name}","source_code":"{source_code}"}}"#,
+                name = escape(&symbol.name),
+                source_code = escape(&symbol.source_code),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let children_json = namespace
+        .children
+        .iter()
+        .map(namespace_to_json)
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let doc_comment_json = match &namespace.doc_comment {
+        Some(doc_comment) => format!(r#""{}""#, escape(doc_comment)),
+        None => "null".to_string(),
+    };
+
+    format!(
+        r#"{{"name":"{name}","doc_comment":{doc_comment_json},"symbols":[{symbols_json}],"children":[{children_json}]}}"#,
+        name = escape(&namespace.name),
+    )
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Symbol;
+
+    struct TestAnalyser;
+
+    impl Analyser for TestAnalyser {
+        fn get_file_extensions(&self) -> Vec<String> {
+            vec!["rs".to_string()]
+        }
+
+        fn get_parser_language(&self) -> tree_sitter::Language {
+            tree_sitter_rust::LANGUAGE.into()
+        }
+
+        fn get_package_metadata(
+            &self,
+            _path: &std::path::Path,
+        ) -> Result<PackageMetadata, LaibraryError> {
+            unimplemented!()
+        }
+
+        fn extract_public_api(
+            &self,
+            _sources: &[crate::types::SourceFile],
+        ) -> Result<Vec<Namespace>, LaibraryError> {
+            unimplemented!()
+        }
+
+        fn format_namespace(&self, _namespace: &Namespace) -> Result<String, LaibraryError> {
+            unimplemented!()
+        }
+    }
+
+    fn test_namespace() -> Namespace {
+        Namespace {
+            name: "test".to_string(),
+            symbols: vec![Symbol {
+                name: "test_fn".to_string(),
+                source_code: "pub fn test_fn() -> () {}".to_string(),
+            }],
+            missing_symbols: Vec::new(),
+            doc_comment: Some("A test module.".to_string()),
+            children: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        let metadata = PackageMetadata {
+            name: "test-lib".to_string(),
+            version: "0.1.0".to_string(),
+            documentation: "A test library.".to_string(),
+        };
+
+        let documentation = JsonFormatter
+            .format(&metadata, &[test_namespace()], &TestAnalyser)
+            .unwrap();
+
+        assert!(documentation.contains(r#""name":"test-lib""#));
+        assert!(documentation.contains(r#""version":"0.1.0""#));
+        assert!(documentation.contains(r#""documentation":"A test library.""#));
+        assert!(documentation.contains(r#""name":"test""#));
+        assert!(documentation.contains(r#""doc_comment":"A test module.""#));
+        assert!(documentation.contains(r#""name":"test_fn""#));
+        assert!(documentation.contains(r#""source_code":"pub fn test_fn() -> () {}""#));
+    }
+
+    #[test]
+    fn test_format_escapes_special_characters() {
+        let metadata = PackageMetadata {
+            name: "test-lib".to_string(),
+            version: "0.1.0".to_string(),
+            documentation: "A test library.".to_string(),
+        };
+
+        let mut namespace = test_namespace();
+        namespace.symbols[0].source_code = "pub fn test_fn() -> &'static str {\n    \"hi\"\n}".to_string();
+
+        let documentation = JsonFormatter
+            .format(&metadata, &[namespace], &TestAnalyser)
+            .unwrap();
+
+        assert!(documentation.contains(r#"\"hi\""#));
+        assert!(documentation.contains("\\n"));
+    }
+
+    #[test]
+    fn test_format_nested_namespaces() {
+        let metadata = PackageMetadata {
+            name: "test-lib".to_string(),
+            version: "0.1.0".to_string(),
+            documentation: "A test library.".to_string(),
+        };
+
+        let mut outer = test_namespace();
+        outer.name = "outer".to_string();
+        let mut inner = test_namespace();
+        inner.name = "outer::inner".to_string();
+        outer.children.push(inner);
+
+        let documentation = JsonFormatter
+            .format(&metadata, &[outer], &TestAnalyser)
+            .unwrap();
+
+        assert!(documentation.contains(r#""name":"outer""#));
+        assert!(documentation.contains(r#""name":"outer::inner""#));
+    }
+}
@@ -0,0 +1,170 @@
+use super::Formatter;
+use crate::analysers::Analyser;
+use crate::error::LaibraryError;
+use crate::types::{Namespace, PackageMetadata};
+
+/// Renders the extracted API as Markdown: a heading per namespace, nested to
+/// match the module tree, with each namespace's symbols in a fenced Rust code
+/// block.
+pub struct MarkdownFormatter;
+
+impl Formatter for MarkdownFormatter {
+    fn format(
+        &self,
+        metadata: &PackageMetadata,
+        namespaces: &[Namespace],
+        analyser: &dyn Analyser,
+    ) -> Result<String, LaibraryError> {
+        let mut output = format!(
+            "# {name} {version}\n\n{documentation}\n",
+            name = metadata.name,
+            version = metadata.version,
+            documentation = metadata.documentation.trim(),
+        );
+
+        for namespace in super::root_namespaces(namespaces) {
+            output.push_str(&render_namespace(namespace, analyser, 2)?);
+        }
+
+        Ok(output)
+    }
+}
+
+/// Render a namespace as a heading, its doc comment, its symbols in a fenced
+/// Rust code block, then its children one heading level deeper.
+fn render_namespace(
+    namespace: &Namespace,
+    analyser: &dyn Analyser,
+    heading_level: usize,
+) -> Result<String, LaibraryError> {
+    let heading = "#".repeat(heading_level.min(6));
+    let mut output = format!("\n{heading} {name}\n", name = namespace.name);
+
+    if let Some(doc_comment) = &namespace.doc_comment {
+        output.push_str(&format!("\n{doc_comment}\n"));
+    }
+
+    let body = analyser.format_namespace(namespace)?;
+    if !body.is_empty() {
+        output.push_str(&format!("\n```rust\n{body}\n```\n"));
+    }
+
+    for child in &namespace.children {
+        output.push_str(&render_namespace(child, analyser, heading_level + 1)?);
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Symbol;
+
+    struct TestAnalyser;
+
+    impl Analyser for TestAnalyser {
+        fn get_file_extensions(&self) -> Vec<String> {
+            vec!["rs".to_string()]
+        }
+
+        fn get_parser_language(&self) -> tree_sitter::Language {
+            tree_sitter_rust::LANGUAGE.into()
+        }
+
+        fn get_package_metadata(
+            &self,
+            _path: &std::path::Path,
+        ) -> Result<PackageMetadata, LaibraryError> {
+            unimplemented!()
+        }
+
+        fn extract_public_api(
+            &self,
+            _sources: &[crate::types::SourceFile],
+        ) -> Result<Vec<Namespace>, LaibraryError> {
+            unimplemented!()
+        }
+
+        fn format_namespace(&self, namespace: &Namespace) -> Result<String, LaibraryError> {
+            Ok(namespace
+                .symbols
+                .iter()
+                .map(|symbol| symbol.source_code.as_str())
+                .collect::<Vec<_>>()
+                .join("\n\n"))
+        }
+    }
+
+    fn test_namespace() -> Namespace {
+        Namespace {
+            name: "test".to_string(),
+            symbols: vec![Symbol {
+                name: "test_fn".to_string(),
+                source_code: "pub fn test_fn() -> () {}".to_string(),
+            }],
+            missing_symbols: Vec::new(),
+            doc_comment: Some("A test module.".to_string()),
+            children: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        let metadata = PackageMetadata {
+            name: "test-lib".to_string(),
+            version: "0.1.0".to_string(),
+            documentation: "A test library.".to_string(),
+        };
+
+        let documentation = MarkdownFormatter
+            .format(&metadata, &[test_namespace()], &TestAnalyser)
+            .unwrap();
+
+        assert!(documentation.contains("# test-lib 0.1.0"));
+        assert!(documentation.contains("A test library."));
+        assert!(documentation.contains("## test"));
+        assert!(documentation.contains("A test module."));
+        assert!(documentation.contains("```rust\npub fn test_fn() -> () {}\n```"));
+    }
+
+    #[test]
+    fn test_format_empty_namespace_has_no_code_block() {
+        let metadata = PackageMetadata {
+            name: "empty-lib".to_string(),
+            version: "0.1.0".to_string(),
+            documentation: "An empty library.".to_string(),
+        };
+
+        let mut namespace = test_namespace();
+        namespace.symbols.clear();
+
+        let documentation = MarkdownFormatter
+            .format(&metadata, &[namespace], &TestAnalyser)
+            .unwrap();
+
+        assert!(!documentation.contains("```"));
+    }
+
+    #[test]
+    fn test_format_nested_namespaces_use_deeper_headings() {
+        let metadata = PackageMetadata {
+            name: "test-lib".to_string(),
+            version: "0.1.0".to_string(),
+            documentation: "A test library.".to_string(),
+        };
+
+        let mut outer = test_namespace();
+        outer.name = "outer".to_string();
+        let mut inner = test_namespace();
+        inner.name = "outer::inner".to_string();
+        outer.children.push(inner);
+
+        let documentation = MarkdownFormatter
+            .format(&metadata, &[outer], &TestAnalyser)
+            .unwrap();
+
+        assert!(documentation.contains("## outer"));
+        assert!(documentation.contains("### outer::inner"));
+    }
+}
@@ -0,0 +1,276 @@
+use super::Formatter;
+use crate::analysers::Analyser;
+use crate::error::LaibraryError;
+use crate::types::{Namespace, PackageMetadata};
+
+/// Renders the extracted API as the original `<library>/<namespace>` XML-ish
+/// document, nesting namespaces to mirror the module tree.
+pub struct XmlFormatter;
+
+impl Formatter for XmlFormatter {
+    fn format(
+        &self,
+        metadata: &PackageMetadata,
+        namespaces: &[Namespace],
+        analyser: &dyn Analyser,
+    ) -> Result<String, LaibraryError> {
+        let mut api_content = String::new();
+        for namespace in super::root_namespaces(namespaces) {
+            api_content.push_str(&render_namespace(namespace, analyser, 2)?);
+        }
+
+        Ok(format!(
+            r#"<library name="{name}" version="{version}">
+    <documentation>
+{documentation}
+    </documentation>
+    <api>
+{api_content}
+    </api>
+</library>"#,
+            name = metadata.name,
+            version = metadata.version,
+            documentation = metadata.documentation.trim()
+        ))
+    }
+}
+
+/// Render a namespace and its children as nested `<namespace>` elements, indenting
+/// each level by `depth` levels of four spaces.
+fn render_namespace(
+    namespace: &Namespace,
+    analyser: &dyn Analyser,
+    depth: usize,
+) -> Result<String, LaibraryError> {
+    let indent = "    ".repeat(depth);
+    let child_indent = "    ".repeat(depth + 1);
+
+    let mut children_content = String::new();
+    for child in &namespace.children {
+        children_content.push_str(&render_namespace(child, analyser, depth + 1)?);
+    }
+
+    Ok(format!(
+        "{indent}<namespace name=\"{name}\">\n{child_indent}{own_content}\n{children_content}{indent}</namespace>\n",
+        name = namespace.name,
+        own_content = analyser.format_namespace(namespace)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Symbol;
+    use tree_sitter::{Parser, Tree};
+
+    struct TestAnalyser;
+
+    impl Analyser for TestAnalyser {
+        fn get_file_extensions(&self) -> Vec<String> {
+            vec!["rs".to_string()]
+        }
+
+        fn get_parser_language(&self) -> tree_sitter::Language {
+            tree_sitter_rust::LANGUAGE.into()
+        }
+
+        fn get_package_metadata(
+            &self,
+            _path: &std::path::Path,
+        ) -> Result<PackageMetadata, LaibraryError> {
+            unimplemented!()
+        }
+
+        fn extract_public_api(
+            &self,
+            _sources: &[crate::types::SourceFile],
+        ) -> Result<Vec<Namespace>, LaibraryError> {
+            unimplemented!()
+        }
+
+        fn format_namespace(&self, namespace: &Namespace) -> Result<String, LaibraryError> {
+            let mut namespace_doc = String::new();
+            for symbol in &namespace.symbols {
+                if !namespace_doc.is_empty() {
+                    namespace_doc.push_str("\n");
+                }
+                namespace_doc.push_str(&symbol.source_code);
+            }
+            Ok(namespace_doc)
+        }
+    }
+
+    fn create_test_namespace(name: &str, content: &str, tree: &Tree) -> Namespace {
+        let root_node = tree.root_node();
+        let mut symbols = Vec::new();
+        let mut cursor = root_node.walk();
+
+        for node in root_node.children(&mut cursor) {
+            if matches!(node.kind(), "function_item" | "struct_item" | "enum_item") {
+                let mut name = String::new();
+                for child in node.children(&mut node.walk()) {
+                    if child.kind() == "identifier" {
+                        name = content[child.start_byte()..child.end_byte()].to_string();
+                        break;
+                    }
+                }
+                symbols.push(Symbol {
+                    name,
+                    source_code: node
+                        .utf8_text(content.as_bytes())
+                        .expect("Failed to get node text")
+                        .to_string(),
+                });
+            }
+        }
+
+        Namespace {
+            name: name.to_string(),
+            symbols,
+            missing_symbols: Vec::new(),
+            doc_comment: None,
+            children: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        let metadata = PackageMetadata {
+            name: "test-lib".to_string(),
+            version: "0.1.0".to_string(),
+            documentation: "A test library.".to_string(),
+        };
+
+        let mut parser = Parser::new();
+        parser
+            .set_language(&tree_sitter_rust::LANGUAGE.into())
+            .unwrap();
+
+        let content = r#"pub fn test() -> () {}
+pub struct Test { field: String }
+pub enum TestEnum { A, B }"#;
+        let tree = parser.parse(content, None).unwrap();
+        let test_namespace = create_test_namespace("test", content, &tree);
+
+        let empty_content = "";
+        let empty_tree = parser.parse(empty_content, None).unwrap();
+        let empty_namespace = create_test_namespace("empty", empty_content, &empty_tree);
+
+        let namespaces = vec![test_namespace, empty_namespace];
+        let analyser = TestAnalyser;
+        let documentation = XmlFormatter.format(&metadata, &namespaces, &analyser).unwrap();
+
+        assert!(
+            documentation.contains(r#"<library name="test-lib" version="0.1.0">"#),
+            "Library tag not found"
+        );
+        assert!(
+            documentation.contains("<documentation>"),
+            "Documentation tag not found"
+        );
+        assert!(
+            documentation.contains("A test library."),
+            "Library documentation not found"
+        );
+        assert!(
+            documentation.contains(r#"<namespace name="test">"#),
+            "namespace tag not found"
+        );
+        assert!(
+            documentation.contains("pub fn test() -> () {}"),
+            "Function not found"
+        );
+        assert!(
+            documentation.contains("pub struct Test { field: String }"),
+            "Struct not found"
+        );
+        assert!(
+            documentation.contains("pub enum TestEnum { A, B }"),
+            "Enum not found"
+        );
+        assert!(
+            documentation.contains("</namespace>"),
+            "namespace closing tag not found"
+        );
+        assert!(
+            documentation.contains("</library>"),
+            "Library closing tag not found"
+        );
+    }
+
+    #[test]
+    fn test_format_empty() {
+        let metadata = PackageMetadata {
+            name: "empty-lib".to_string(),
+            version: "0.1.0".to_string(),
+            documentation: "An empty library.".to_string(),
+        };
+
+        let analyser = TestAnalyser;
+        let documentation = XmlFormatter.format(&metadata, &[], &analyser).unwrap();
+
+        assert!(
+            documentation.contains(r#"<library name="empty-lib" version="0.1.0">"#),
+            "Library tag not found"
+        );
+        assert!(
+            documentation.contains("<documentation>"),
+            "Documentation tag not found"
+        );
+        assert!(
+            documentation.contains("An empty library."),
+            "Library documentation not found"
+        );
+        assert!(
+            !documentation.contains("<namespace"),
+            "Unexpected namespace tag found"
+        );
+    }
+
+    #[test]
+    fn test_format_nested_namespaces() {
+        let metadata = PackageMetadata {
+            name: "test-lib".to_string(),
+            version: "0.1.0".to_string(),
+            documentation: "A test library.".to_string(),
+        };
+
+        let mut parser = Parser::new();
+        parser
+            .set_language(&tree_sitter_rust::LANGUAGE.into())
+            .unwrap();
+
+        let inner_content = "pub fn inner_fn() -> () {}";
+        let inner_tree = parser.parse(inner_content, None).unwrap();
+        let inner_namespace = create_test_namespace("test::inner", inner_content, &inner_tree);
+
+        let outer_content = "pub fn outer_fn() -> () {}";
+        let outer_tree = parser.parse(outer_content, None).unwrap();
+        let mut outer_namespace = create_test_namespace("test", outer_content, &outer_tree);
+        outer_namespace.children.push(inner_namespace);
+
+        let analyser = TestAnalyser;
+        let documentation = XmlFormatter
+            .format(&metadata, &[outer_namespace], &analyser)
+            .unwrap();
+
+        let outer_index = documentation
+            .find(r#"<namespace name="test">"#)
+            .expect("outer namespace tag not found");
+        let inner_index = documentation
+            .find(r#"<namespace name="test::inner">"#)
+            .expect("inner namespace tag not found");
+        assert!(
+            inner_index > outer_index,
+            "inner namespace should be nested inside the outer one"
+        );
+        assert!(
+            documentation.contains("pub fn outer_fn() -> () {}"),
+            "outer symbol not found"
+        );
+        assert!(
+            documentation.contains("pub fn inner_fn() -> () {}"),
+            "inner symbol not found"
+        );
+    }
+}
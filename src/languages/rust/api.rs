@@ -3,9 +3,14 @@ use crate::types::Namespace;
 use std::path::Path;
 use tree_sitter::Parser;
 
+mod doc_link_resolution;
+mod symbol_collection;
+mod symbol_resolution;
+
+use self::doc_link_resolution::resolve_doc_links;
+use self::symbol_collection::collect_symbols;
+use self::symbol_resolution::resolve_symbols;
 use super::namespace_construction::construct_namespaces;
-use super::symbol_collection::collect_symbols;
-use super::symbol_resolution::resolve_symbols;
 
 pub fn build_public_api(
     entry_point: &Path,
@@ -13,7 +18,8 @@ pub fn build_public_api(
     parser: &mut Parser,
 ) -> Result<Vec<Namespace>, LaibraryError> {
     let raw_namespaces = collect_symbols(entry_point, parser)?;
-    let resolution = resolve_symbols(&raw_namespaces)?;
+    let mut resolution = resolve_symbols(&raw_namespaces)?;
+    resolve_doc_links(&mut resolution);
     let namespaces = construct_namespaces(resolution, crate_name);
     Ok(namespaces)
 }
@@ -82,4 +88,56 @@ pub enum Format {
         assert_eq!(module.symbols.len(), 1);
         assert!(module.symbols.iter().any(|s| s.name == "Format"));
     }
+
+    #[test]
+    fn test_integration_glob_and_renamed_reexports() {
+        let temp_dir = create_temp_dir();
+        let lib_rs = temp_dir.path().join("src").join("lib.rs");
+        let module_rs = temp_dir.path().join("src").join("module.rs");
+
+        create_file(
+            &lib_rs,
+            r#"
+pub mod module;
+pub use module::*;
+pub use module::Format as FileFormat;
+"#,
+        );
+        create_file(
+            &module_rs,
+            r#"
+pub enum Format {
+    Text,
+    Binary,
+}
+
+pub fn process() -> () {}
+"#,
+        );
+
+        let mut parser = setup_parser();
+        let namespaces = build_public_api(&lib_rs, STUB_CRATE_NAME, &mut parser).unwrap();
+
+        let root = namespaces
+            .iter()
+            .find(|n| n.name == STUB_CRATE_NAME)
+            .unwrap();
+        assert!(
+            root.symbols.iter().any(|s| s.name == "Format"),
+            "glob re-export should pull Format into the root namespace"
+        );
+        assert!(
+            root.symbols.iter().any(|s| s.name == "process"),
+            "glob re-export should pull process into the root namespace"
+        );
+        assert!(
+            root.symbols.iter().any(|s| s.name == "FileFormat"),
+            "renamed re-export should show up under its public name"
+        );
+        assert_eq!(
+            root.get_symbol("FileFormat").unwrap().source_code,
+            root.get_symbol("Format").unwrap().source_code,
+            "renamed re-export should keep the original source code"
+        );
+    }
 }
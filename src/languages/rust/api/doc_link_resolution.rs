@@ -0,0 +1,286 @@
+use super::symbol_resolution::{ResolvedSymbol, SymbolResolution};
+use std::collections::HashMap;
+
+/// For each symbol name, every module path it is reachable from paired with its
+/// fully-qualified `crate::...` path.
+type SymbolIndex = HashMap<String, Vec<(String, String)>>;
+
+/// Rewrite rustdoc intra-doc links in every doc comment to the fully-qualified
+/// `crate::module::Item` path of the symbol they reference, e.g. `` [`Format`] ``,
+/// `[text](Format)` or `` [`process`](fn@process) `` all resolve to something like
+/// `` `crate::formats::Format` ``. A link that doesn't resolve to a known symbol
+/// (an external URL, or a name this pass can't find) is left untouched.
+pub fn resolve_doc_links(symbol_resolution: &mut SymbolResolution) {
+    let index = build_symbol_index(&symbol_resolution.symbols);
+
+    for (module_path, doc_comment) in symbol_resolution.doc_comments.iter_mut() {
+        *doc_comment = rewrite_links(doc_comment, module_path, &index);
+    }
+}
+
+fn build_symbol_index(symbols: &[ResolvedSymbol]) -> SymbolIndex {
+    let mut index: SymbolIndex = HashMap::new();
+    for resolved_symbol in symbols {
+        for module_path in &resolved_symbol.modules {
+            let qualified = if module_path.is_empty() {
+                format!("crate::{}", resolved_symbol.symbol.name)
+            } else {
+                format!("crate::{}::{}", module_path, resolved_symbol.symbol.name)
+            };
+            index
+                .entry(resolved_symbol.symbol.name.clone())
+                .or_default()
+                .push((module_path.clone(), qualified));
+        }
+    }
+    index
+}
+
+/// Rewrite every intra-doc link found in `doc_comment`. `module_path` is the
+/// module this doc comment belongs to, used to scope unqualified link targets.
+fn rewrite_links(doc_comment: &str, module_path: &str, index: &SymbolIndex) -> String {
+    let chars: Vec<char> = doc_comment.chars().collect();
+    let mut result = String::with_capacity(doc_comment.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i] == '[' {
+            if let Some((target, consumed)) = parse_markdown_link(&chars[i..]) {
+                match resolve_target(&target, module_path, index) {
+                    Some(resolved) => {
+                        result.push('`');
+                        result.push_str(&resolved);
+                        result.push('`');
+                    }
+                    None => result.extend(&chars[i..i + consumed]),
+                }
+                i += consumed;
+                continue;
+            }
+        }
+        result.push(chars[i]);
+        i += 1;
+    }
+
+    result
+}
+
+/// Parse a markdown link starting at `chars[0] == '['`: either the inline form
+/// `[text](target)`, or the shortcut-reference form rustdoc treats as an
+/// intra-doc link, `` [`target`] `` (no parens, link text is the target,
+/// optionally wrapped in a code span). Returns the link target and how many
+/// characters the whole link consumed. Any bracketed text is read as a link;
+/// `None` is returned only when no closing `]` follows (or an inline link's
+/// `(` has no closing `)`).
+fn parse_markdown_link(chars: &[char]) -> Option<(String, usize)> {
+    let close = chars.iter().position(|&c| c == ']')?;
+    let text: &[char] = &chars[1..close];
+
+    if chars.get(close + 1) == Some(&'(') {
+        let paren_start = close + 2;
+        let paren_close = chars[paren_start..].iter().position(|&c| c == ')')? + paren_start;
+        let target: String = chars[paren_start..paren_close].iter().collect();
+        return Some((target, paren_close + 1));
+    }
+
+    let target: String = text
+        .iter()
+        .collect::<String>()
+        .trim_matches('`')
+        .to_string();
+    Some((target, close + 1))
+}
+
+/// Resolve a single intra-doc link target to a fully-qualified symbol path.
+/// Looks the target up relative to `module_path` first, then each ancestor
+/// module in turn, then the crate root; an ambiguous name (several unrelated
+/// symbols share it) resolves to whichever is namespace-nearest.
+fn resolve_target(target: &str, module_path: &str, index: &SymbolIndex) -> Option<String> {
+    if target.contains("://") {
+        return None; // an external URL, not an intra-doc link
+    }
+
+    let target = target
+        .trim_start_matches("fn@")
+        .trim_start_matches("struct@")
+        .trim_start_matches("enum@")
+        .trim_start_matches("mod@")
+        .trim_start_matches("::");
+
+    let (base, method) = match target.split_once("::") {
+        Some((base, method)) => (base, Some(method)),
+        None => (target, None),
+    };
+
+    let candidates = index.get(base)?;
+    let scope = ancestor_scopes(module_path).into_iter().find(|scope| {
+        candidates
+            .iter()
+            .any(|(candidate_module, _)| candidate_module == scope)
+    })?;
+    let (_, qualified) = candidates
+        .iter()
+        .find(|(candidate_module, _)| candidate_module == &scope)?;
+
+    Some(match method {
+        Some(method) => format!("{}::{}", qualified, method),
+        None => qualified.clone(),
+    })
+}
+
+/// `module_path` and each of its ancestors up to the crate root, nearest first,
+/// e.g. `"outer::inner"` yields `["outer::inner", "outer", ""]`.
+fn ancestor_scopes(module_path: &str) -> Vec<String> {
+    let mut scopes = vec![module_path.to_string()];
+    let mut current = module_path;
+    while let Some((parent, _)) = current.rsplit_once("::") {
+        scopes.push(parent.to_string());
+        current = parent;
+    }
+    if !module_path.is_empty() {
+        scopes.push(String::new());
+    }
+    scopes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::stub_symbol_with_name;
+
+    fn resolution(doc_comments: HashMap<String, String>, symbols: Vec<ResolvedSymbol>) -> SymbolResolution {
+        SymbolResolution {
+            symbols,
+            doc_comments,
+        }
+    }
+
+    #[test]
+    fn shortcut_reference_link_is_resolved() {
+        let mut resolved = resolution(
+            HashMap::from([(String::new(), "See [`Format`] for details.".to_string())]),
+            vec![ResolvedSymbol {
+                symbol: stub_symbol_with_name("Format"),
+                modules: vec![String::new()],
+            }],
+        );
+
+        resolve_doc_links(&mut resolved);
+
+        assert_eq!(
+            resolved.doc_comments[""],
+            "See `crate::Format` for details."
+        );
+    }
+
+    #[test]
+    fn inline_link_is_resolved_to_its_target() {
+        let mut resolved = resolution(
+            HashMap::from([(String::new(), "See the [format type](Format).".to_string())]),
+            vec![ResolvedSymbol {
+                symbol: stub_symbol_with_name("Format"),
+                modules: vec![String::new()],
+            }],
+        );
+
+        resolve_doc_links(&mut resolved);
+
+        assert_eq!(
+            resolved.doc_comments[""],
+            "See the `crate::Format`."
+        );
+    }
+
+    #[test]
+    fn disambiguated_link_keeps_the_fn_target() {
+        let mut resolved = resolution(
+            HashMap::from([(String::new(), "Call [`process`](fn@process) first.".to_string())]),
+            vec![ResolvedSymbol {
+                symbol: stub_symbol_with_name("process"),
+                modules: vec![String::new()],
+            }],
+        );
+
+        resolve_doc_links(&mut resolved);
+
+        assert_eq!(
+            resolved.doc_comments[""],
+            "Call `crate::process` first."
+        );
+    }
+
+    #[test]
+    fn nearest_module_wins_over_crate_root() {
+        let mut resolved = resolution(
+            HashMap::from([("outer::inner".to_string(), "Uses [`Format`].".to_string())]),
+            vec![
+                ResolvedSymbol {
+                    symbol: stub_symbol_with_name("Format"),
+                    modules: vec![String::new()],
+                },
+                ResolvedSymbol {
+                    symbol: stub_symbol_with_name("Format"),
+                    modules: vec!["outer".to_string()],
+                },
+            ],
+        );
+
+        resolve_doc_links(&mut resolved);
+
+        assert_eq!(
+            resolved.doc_comments["outer::inner"],
+            "Uses `crate::outer::Format`."
+        );
+    }
+
+    #[test]
+    fn method_path_keeps_its_method_suffix() {
+        let mut resolved = resolution(
+            HashMap::from([(String::new(), "See [`Format::parse`].".to_string())]),
+            vec![ResolvedSymbol {
+                symbol: stub_symbol_with_name("Format"),
+                modules: vec![String::new()],
+            }],
+        );
+
+        resolve_doc_links(&mut resolved);
+
+        assert_eq!(
+            resolved.doc_comments[""],
+            "See `crate::Format::parse`."
+        );
+    }
+
+    #[test]
+    fn external_url_is_left_untouched() {
+        let mut resolved = resolution(
+            HashMap::from([(
+                String::new(),
+                "See [the spec](https://example.com/spec).".to_string(),
+            )]),
+            Vec::new(),
+        );
+
+        resolve_doc_links(&mut resolved);
+
+        assert_eq!(
+            resolved.doc_comments[""],
+            "See [the spec](https://example.com/spec)."
+        );
+    }
+
+    #[test]
+    fn unresolved_link_is_left_untouched() {
+        let mut resolved = resolution(
+            HashMap::from([(String::new(), "See [`Nonexistent`] for details.".to_string())]),
+            Vec::new(),
+        );
+
+        resolve_doc_links(&mut resolved);
+
+        assert_eq!(
+            resolved.doc_comments[""],
+            "See [`Nonexistent`] for details."
+        );
+    }
+}
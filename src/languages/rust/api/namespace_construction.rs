@@ -3,38 +3,102 @@ use crate::types::Namespace;
 use std::collections::HashMap;
 
 /// Construct the final namespace hierarchy using the resolved symbols.
+///
+/// A resolved symbol can target several module paths at once (re-exports), and a
+/// module path can nest arbitrarily deep even when an intermediate module exports
+/// nothing of its own. This builds one `Namespace` per module path encountered,
+/// synthesizing empty ancestor modules so the hierarchy has no holes, then links each
+/// namespace to its parent's `children`. The crate root namespace itself is only
+/// created when the root module has symbols of its own; otherwise each top-level
+/// module's namespace is a root of the tree. The result stays a flat `Vec<Namespace>`
+/// (every namespace, root or nested, is reachable by name), but root namespaces now
+/// carry their full descendant tree in `children`.
 pub fn construct_namespaces(
     symbol_resolution: SymbolResolution,
     crate_name: &str,
 ) -> Vec<Namespace> {
     let mut namespace_map: HashMap<String, Namespace> = HashMap::new();
 
-    // Group symbols by namespace
-    symbol_resolution
+    let namespace_name_for = |module_path: &str| -> String {
+        if module_path.is_empty() {
+            crate_name.to_string()
+        } else {
+            format!("{}::{}", crate_name, module_path)
+        }
+    };
+
+    // Group symbols by namespace, creating a node for every module path they target.
+    for resolved_symbol in &symbol_resolution.symbols {
+        for module_path in &resolved_symbol.modules {
+            let namespace_name = namespace_name_for(module_path);
+            let namespace = namespace_map
+                .entry(namespace_name.clone())
+                .or_insert_with(|| Namespace {
+                    name: namespace_name,
+                    symbols: Vec::new(),
+                    missing_symbols: Vec::new(),
+                    doc_comment: symbol_resolution.doc_comments.get(module_path).cloned(),
+                    children: Vec::new(),
+                });
+            namespace.symbols.push(resolved_symbol.symbol.clone());
+        }
+    }
+
+    // Synthesize ancestors for any module path whose own parent was never used
+    // directly, e.g. `outer::inner` is used but `outer` itself exports nothing.
+    let module_paths: Vec<String> = symbol_resolution
         .symbols
         .iter()
-        .for_each(|resolved_symbol| {
-            resolved_symbol.modules.iter().for_each(|module_path| {
-                let namespace_name = if module_path.is_empty() {
-                    crate_name.to_string()
-                } else {
-                    format!("{}::{}", crate_name, module_path)
-                };
-                let namespace = namespace_map
-                    .entry(namespace_name.clone())
-                    .or_insert_with(|| Namespace {
-                        name: namespace_name,
-                        symbols: Vec::new(),
-                        missing_symbols: Vec::new(),
-                        doc_comment: symbol_resolution.doc_comments.get(module_path).cloned(),
-                    });
-                namespace.symbols.push(resolved_symbol.symbol.clone());
-            });
-        });
+        .flat_map(|resolved_symbol| resolved_symbol.modules.iter().cloned())
+        .collect();
+    for module_path in &module_paths {
+        for ancestor in module_ancestors(module_path) {
+            let namespace_name = namespace_name_for(&ancestor);
+            namespace_map
+                .entry(namespace_name.clone())
+                .or_insert_with(|| Namespace {
+                    name: namespace_name,
+                    symbols: Vec::new(),
+                    missing_symbols: Vec::new(),
+                    doc_comment: symbol_resolution.doc_comments.get(&ancestor).cloned(),
+                    children: Vec::new(),
+                });
+        }
+    }
+
+    // Attach each namespace to its parent's children, deepest first so a namespace
+    // already carries its own descendants by the time it is cloned into its parent.
+    let mut names: Vec<String> = namespace_map.keys().cloned().collect();
+    names.sort_by_key(|name| std::cmp::Reverse(name.matches("::").count()));
+    for name in names {
+        if name == crate_name {
+            continue;
+        }
+        let Some((parent_name, _)) = name.rsplit_once("::") else {
+            continue;
+        };
+        if let Some(child) = namespace_map.get(&name).cloned() {
+            if let Some(parent) = namespace_map.get_mut(parent_name) {
+                parent.children.push(child);
+            }
+        }
+    }
 
     namespace_map.into_values().collect()
 }
 
+/// Every proper ancestor of a `::`-separated module path, innermost first, e.g.
+/// `"outer::inner::deep"` yields `["outer::inner", "outer"]`.
+fn module_ancestors(module_path: &str) -> Vec<String> {
+    let mut ancestors = Vec::new();
+    let mut current = module_path;
+    while let Some((parent, _)) = current.rsplit_once("::") {
+        ancestors.push(parent.to_string());
+        current = parent;
+    }
+    ancestors
+}
+
 #[cfg(test)]
 mod tests {
     use assertables::assert_contains;
@@ -169,6 +233,55 @@ mod tests {
         assert_eq!(inner_namespace.symbols, vec![symbol]);
     }
 
+    #[test]
+    fn missing_intermediate_namespace_is_synthesized() {
+        let symbol = stub_symbol_with_name(STUB_SYMBOL_NAME);
+        let resolved_symbols = vec![ResolvedSymbol {
+            symbol: symbol.clone(),
+            modules: vec!["outer::inner".to_string()],
+        }];
+
+        let namespaces = construct_namespaces(
+            SymbolResolution {
+                symbols: resolved_symbols,
+                doc_comments: HashMap::new(),
+            },
+            STUB_CRATE_NAME,
+        );
+
+        assert_eq!(namespaces.len(), 2);
+        let outer_namespace =
+            get_namespace(&format!("{}::outer", STUB_CRATE_NAME), &namespaces).unwrap();
+        assert!(outer_namespace.symbols.is_empty());
+        let inner_namespace =
+            get_namespace(&format!("{}::outer::inner", STUB_CRATE_NAME), &namespaces).unwrap();
+        assert_eq!(inner_namespace.symbols, vec![symbol]);
+    }
+
+    #[test]
+    fn namespaces_nest_under_their_parent() {
+        let symbol = stub_symbol_with_name(STUB_SYMBOL_NAME);
+        let resolved_symbols = vec![ResolvedSymbol {
+            symbol: symbol.clone(),
+            modules: vec!["outer::inner".to_string()],
+        }];
+
+        let namespaces = construct_namespaces(
+            SymbolResolution {
+                symbols: resolved_symbols,
+                doc_comments: HashMap::new(),
+            },
+            STUB_CRATE_NAME,
+        );
+
+        let outer_namespace =
+            get_namespace(&format!("{}::outer", STUB_CRATE_NAME), &namespaces).unwrap();
+        assert_eq!(outer_namespace.children.len(), 1);
+        let child = &outer_namespace.children[0];
+        assert_eq!(child.name, format!("{}::outer::inner", STUB_CRATE_NAME));
+        assert_eq!(child.symbols, vec![symbol]);
+    }
+
     #[test]
     fn doc_comment() {
         let doc_comment = "This is a stub doc comment";
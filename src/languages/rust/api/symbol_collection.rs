@@ -0,0 +1,218 @@
+use crate::error::LaibraryError;
+use crate::types::Symbol;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tree_sitter::{Node, Parser};
+
+/// A `pub use` re-export collected from a module, before it is resolved
+/// against the symbols actually defined elsewhere in the crate.
+#[derive(Debug, Clone)]
+pub enum ReExport {
+    /// `pub use module::Item;`, or `pub use module::Item as Alias;` when
+    /// `alias` is set.
+    Single {
+        path: String,
+        name: String,
+        alias: Option<String>,
+    },
+    /// `pub use module::*;`
+    Glob { path: String },
+}
+
+/// Everything collected from a single module file, before `use` statements
+/// are resolved into concrete symbols.
+#[derive(Debug, Clone, Default)]
+pub struct RawModule {
+    pub symbols: Vec<Symbol>,
+    pub re_exports: Vec<ReExport>,
+    pub doc_comment: Option<String>,
+}
+
+/// Every module collected from a crate, keyed by its `::`-joined module path
+/// (the empty string is the crate root).
+#[derive(Debug, Clone, Default)]
+pub struct RawNamespaces {
+    pub modules: HashMap<String, RawModule>,
+}
+
+/// Walk a crate starting from its entry point (`lib.rs`/`main.rs`), following
+/// `mod` declarations to every module file, and collect each module's public
+/// items and `use` statements.
+pub fn collect_symbols(
+    entry_point: &Path,
+    parser: &mut Parser,
+) -> Result<RawNamespaces, LaibraryError> {
+    let mut raw_namespaces = RawNamespaces::default();
+    collect_module(entry_point, "", parser, &mut raw_namespaces)?;
+    Ok(raw_namespaces)
+}
+
+fn collect_module(
+    file_path: &Path,
+    module_path: &str,
+    parser: &mut Parser,
+    raw_namespaces: &mut RawNamespaces,
+) -> Result<(), LaibraryError> {
+    let content = std::fs::read_to_string(file_path)
+        .map_err(|error| LaibraryError::Parse(format!("{}: {error}", file_path.display())))?;
+    let tree = parser
+        .parse(&content, None)
+        .ok_or_else(|| LaibraryError::Parse(format!("failed to parse {}", file_path.display())))?;
+
+    let mut raw_module = RawModule::default();
+    let mut submodules = Vec::new();
+    let mut cursor = tree.root_node().walk();
+
+    for node in tree.root_node().children(&mut cursor) {
+        match node.kind() {
+            "function_item" | "struct_item" | "enum_item" | "trait_item" | "type_item"
+            | "const_item" => {
+                if is_public(&node) {
+                    if let Some(name) = item_name(&node, &content) {
+                        raw_module.symbols.push(Symbol {
+                            name,
+                            source_code: node_text(&node, &content),
+                        });
+                    }
+                }
+            }
+            "use_declaration" if is_public(&node) => {
+                raw_module
+                    .re_exports
+                    .extend(parse_use_declaration(&node, &content));
+            }
+            "mod_item" => {
+                if let Some(name) = item_name(&node, &content) {
+                    let mut body_cursor = node.walk();
+                    let has_inline_body = node
+                        .children(&mut body_cursor)
+                        .any(|child| child.kind() == "declaration_list");
+                    if !has_inline_body {
+                        submodules.push(name);
+                    }
+                }
+            }
+            "line_comment" => {
+                if let Some(doc) = node_text(&node, &content).strip_prefix("//!") {
+                    append_doc_comment(&mut raw_module.doc_comment, doc.trim());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    raw_namespaces
+        .modules
+        .insert(module_path.to_string(), raw_module);
+
+    for name in submodules {
+        let child_path = if module_path.is_empty() {
+            name.clone()
+        } else {
+            format!("{module_path}::{name}")
+        };
+        let child_file = resolve_module_file(file_path, &name)?;
+        collect_module(&child_file, &child_path, parser, raw_namespaces)?;
+    }
+
+    Ok(())
+}
+
+fn append_doc_comment(doc_comment: &mut Option<String>, line: &str) {
+    *doc_comment = Some(match doc_comment.take() {
+        Some(existing) => format!("{existing}\n{line}"),
+        None => line.to_string(),
+    });
+}
+
+/// Resolve `mod name;` to the file it declares: `name.rs` next to the current
+/// file, or failing that `name/mod.rs`.
+fn resolve_module_file(current_file: &Path, name: &str) -> Result<PathBuf, LaibraryError> {
+    let dir = current_file.parent().unwrap_or_else(|| Path::new("."));
+    let sibling = dir.join(format!("{name}.rs"));
+    if sibling.exists() {
+        return Ok(sibling);
+    }
+    let nested = dir.join(name).join("mod.rs");
+    if nested.exists() {
+        return Ok(nested);
+    }
+    Err(LaibraryError::Parse(format!(
+        "could not find module file for `{name}` next to {}",
+        current_file.display()
+    )))
+}
+
+fn is_public(node: &Node) -> bool {
+    let mut cursor = node.walk();
+    node.children(&mut cursor)
+        .any(|child| child.kind() == "visibility_modifier")
+}
+
+fn item_name(node: &Node, content: &str) -> Option<String> {
+    let mut cursor = node.walk();
+    node.children(&mut cursor)
+        .find(|child| matches!(child.kind(), "identifier" | "type_identifier"))
+        .map(|child| node_text(&child, content))
+}
+
+fn node_text(node: &Node, content: &str) -> String {
+    node.utf8_text(content.as_bytes())
+        .expect("source span should be valid utf8")
+        .to_string()
+}
+
+/// Parse a `pub use ...;` declaration into its re-exports. A single `use` binds
+/// either one path (`pub use a::b::Item;`, optionally renamed with `as`) or a
+/// glob (`pub use a::b::*;`).
+fn parse_use_declaration(node: &Node, content: &str) -> Vec<ReExport> {
+    let mut cursor = node.walk();
+    let Some(argument) = node.children(&mut cursor).find(|child| {
+        matches!(
+            child.kind(),
+            "use_wildcard" | "use_as_clause" | "scoped_identifier" | "identifier"
+        )
+    }) else {
+        return Vec::new();
+    };
+
+    match argument.kind() {
+        "use_wildcard" => {
+            let path = argument
+                .child(0)
+                .map(|child| node_text(&child, content))
+                .unwrap_or_default();
+            vec![ReExport::Glob { path }]
+        }
+        "use_as_clause" => {
+            let mut clause_cursor = argument.walk();
+            let children: Vec<Node> = argument.children(&mut clause_cursor).collect();
+            let Some(target) = children.first() else {
+                return Vec::new();
+            };
+            let alias = children
+                .iter()
+                .find(|child| child.kind() == "identifier")
+                .map(|child| node_text(child, content));
+            let (path, name) = split_scoped_path(target, content);
+            vec![ReExport::Single { path, name, alias }]
+        }
+        _ => {
+            let (path, name) = split_scoped_path(&argument, content);
+            vec![ReExport::Single {
+                path,
+                name,
+                alias: None,
+            }]
+        }
+    }
+}
+
+/// Split `a::b::Item` into its module path (`a::b`) and item name (`Item`).
+fn split_scoped_path(node: &Node, content: &str) -> (String, String) {
+    let text = node_text(node, content);
+    match text.rsplit_once("::") {
+        Some((path, name)) => (path.to_string(), name.to_string()),
+        None => (String::new(), text),
+    }
+}
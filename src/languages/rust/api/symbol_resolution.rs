@@ -0,0 +1,183 @@
+use super::symbol_collection::{RawNamespaces, ReExport};
+use crate::error::LaibraryError;
+use crate::types::Symbol;
+use std::collections::HashMap;
+
+/// A symbol together with every module path it is publicly reachable from,
+/// whether that's where it's defined or a re-export elsewhere in the crate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedSymbol {
+    pub symbol: Symbol,
+    pub modules: Vec<String>,
+}
+
+/// The crate's full set of resolved symbols, plus the doc comment collected
+/// for each module path.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolResolution {
+    pub symbols: Vec<ResolvedSymbol>,
+    pub doc_comments: HashMap<String, String>,
+}
+
+/// Resolve the raw, per-module symbols and `use` statements collected by
+/// [`collect_symbols`](super::symbol_collection::collect_symbols) into the
+/// crate's public symbols, following re-exports to the modules they make a
+/// symbol reachable from.
+///
+/// A `pub use module::Item;` makes `Item` reachable from the re-exporting
+/// module too, so it's recorded on the same `ResolvedSymbol` rather than
+/// duplicating its definition. A `pub use module::*;` does the same for every
+/// symbol defined in `module`. A rename (`pub use module::Item as Alias;`)
+/// keeps the original `source_code` but is tracked under the new public name,
+/// since a `ResolvedSymbol` carries a single name across all of its modules.
+pub fn resolve_symbols(raw_namespaces: &RawNamespaces) -> Result<SymbolResolution, LaibraryError> {
+    let mut symbols: Vec<ResolvedSymbol> = Vec::new();
+    let mut defined: HashMap<(String, String), usize> = HashMap::new();
+    let mut doc_comments = HashMap::new();
+
+    for (module_path, module) in &raw_namespaces.modules {
+        if let Some(doc_comment) = &module.doc_comment {
+            doc_comments.insert(module_path.clone(), doc_comment.clone());
+        }
+        for symbol in &module.symbols {
+            let index = symbols.len();
+            symbols.push(ResolvedSymbol {
+                symbol: symbol.clone(),
+                modules: vec![module_path.clone()],
+            });
+            defined.insert((module_path.clone(), symbol.name.clone()), index);
+        }
+    }
+
+    for (module_path, module) in &raw_namespaces.modules {
+        for re_export in &module.re_exports {
+            match re_export {
+                ReExport::Single { path, name, alias } => {
+                    if let Some(&index) = defined.get(&(path.clone(), name.clone())) {
+                        let public_name = alias.clone().unwrap_or_else(|| name.clone());
+                        add_export(&mut symbols, index, module_path, &public_name);
+                    }
+                }
+                ReExport::Glob { path } => {
+                    let Some(target) = raw_namespaces.modules.get(path) else {
+                        continue;
+                    };
+                    for symbol in &target.symbols {
+                        if let Some(&index) = defined.get(&(path.clone(), symbol.name.clone())) {
+                            add_export(&mut symbols, index, module_path, &symbol.name);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(SymbolResolution {
+        symbols,
+        doc_comments,
+    })
+}
+
+/// Record that the symbol at `symbols[index]` is also reachable, as
+/// `public_name`, from `module_path`.
+fn add_export(symbols: &mut Vec<ResolvedSymbol>, index: usize, module_path: &str, public_name: &str) {
+    if symbols[index].symbol.name == public_name {
+        if !symbols[index].modules.iter().any(|module| module == module_path) {
+            symbols[index].modules.push(module_path.to_string());
+        }
+        return;
+    }
+
+    // The public name changed, so this can't share the original `ResolvedSymbol`
+    // (it would have to carry two names at once) - record it as its own entry
+    // that keeps the original `source_code`.
+    symbols.push(ResolvedSymbol {
+        symbol: Symbol {
+            name: public_name.to_string(),
+            source_code: symbols[index].symbol.source_code.clone(),
+        },
+        modules: vec![module_path.to_string()],
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::symbol_collection::RawModule;
+
+    fn module_with_symbol(name: &str) -> RawModule {
+        RawModule {
+            symbols: vec![Symbol {
+                name: name.to_string(),
+                source_code: format!("pub struct {name};"),
+            }],
+            re_exports: Vec::new(),
+            doc_comment: None,
+        }
+    }
+
+    #[test]
+    fn glob_reexport_pulls_in_every_public_symbol() {
+        let mut raw_namespaces = RawNamespaces::default();
+        raw_namespaces
+            .modules
+            .insert("inner".to_string(), module_with_symbol("Format"));
+        raw_namespaces.modules.insert(
+            String::new(),
+            RawModule {
+                symbols: Vec::new(),
+                re_exports: vec![ReExport::Glob {
+                    path: "inner".to_string(),
+                }],
+                doc_comment: None,
+            },
+        );
+
+        let resolution = resolve_symbols(&raw_namespaces).unwrap();
+
+        let format_symbol = resolution
+            .symbols
+            .iter()
+            .find(|resolved| resolved.symbol.name == "Format")
+            .unwrap();
+        assert!(format_symbol.modules.contains(&"inner".to_string()));
+        assert!(format_symbol.modules.contains(&String::new()));
+    }
+
+    #[test]
+    fn renamed_reexport_keeps_source_under_the_new_name() {
+        let mut raw_namespaces = RawNamespaces::default();
+        raw_namespaces
+            .modules
+            .insert("inner".to_string(), module_with_symbol("Format"));
+        raw_namespaces.modules.insert(
+            String::new(),
+            RawModule {
+                symbols: Vec::new(),
+                re_exports: vec![ReExport::Single {
+                    path: "inner".to_string(),
+                    name: "Format".to_string(),
+                    alias: Some("FileFormat".to_string()),
+                }],
+                doc_comment: None,
+            },
+        );
+
+        let resolution = resolve_symbols(&raw_namespaces).unwrap();
+
+        let original = resolution
+            .symbols
+            .iter()
+            .find(|resolved| resolved.symbol.name == "Format")
+            .unwrap();
+        assert_eq!(original.modules, vec!["inner".to_string()]);
+
+        let renamed = resolution
+            .symbols
+            .iter()
+            .find(|resolved| resolved.symbol.name == "FileFormat")
+            .unwrap();
+        assert_eq!(renamed.modules, vec![String::new()]);
+        assert_eq!(renamed.symbol.source_code, "pub struct Format;");
+    }
+}
@@ -29,6 +29,9 @@ mod tests {
                     source_code: "pub enum TestEnum { A, B }".to_string(),
                 },
             ],
+            missing_symbols: Vec::new(),
+            doc_comment: None,
+            children: Vec::new(),
         };
 
         let formatted = format_module(&module);
@@ -5,27 +5,53 @@ mod languages;
 mod listing;
 mod parsing;
 mod types;
+mod workspace;
 
+use crate::analysers::Analyser;
 use crate::error::LaibraryError;
-use crate::formatting::format_library_context;
+use crate::formatting::get_formatter;
 use crate::languages::get_analyser;
 use crate::listing::get_source_file_paths;
 use crate::parsing::get_parser;
-use std::path::Path;
+use crate::types::{Namespace, PackageMetadata};
+use crate::workspace::{find_workspace_members, has_package_table, virtual_workspace_metadata};
+use std::path::{Path, PathBuf};
 
 /// Generate API documentation for a library in the specified language.
 ///
 /// # Arguments
 ///
 /// * `language` - The programming language of the library
-/// * `path` - Path to the library's root directory
+/// * `path` - Path to the library's root directory, or to a Cargo workspace root
+/// * `format` - The output format to render the documentation as (e.g. `"xml"`, `"json"`, `"markdown"`)
 ///
 /// # Returns
 ///
 /// Returns a Result containing the generated documentation as a string, or an error if something went wrong.
-pub fn generate_documentation(language: &str, path: &Path) -> Result<String, LaibraryError> {
+pub fn generate_documentation(
+    language: &str,
+    path: &Path,
+    format: &str,
+) -> Result<String, LaibraryError> {
     let analyser = get_analyser(language)?;
+    let formatter = get_formatter(format)?;
 
+    // Cargo workspaces are a Rust-specific concept; other languages always go
+    // through the single-package path.
+    let (metadata, namespaces) = match find_workspace_members(path) {
+        Some(members) if language == "rust" => {
+            generate_workspace_documentation(analyser.as_ref(), path, &members)?
+        }
+        _ => generate_package_documentation(analyser.as_ref(), path)?,
+    };
+
+    formatter.format(&metadata, &namespaces, analyser.as_ref())
+}
+
+fn generate_package_documentation(
+    analyser: &dyn Analyser,
+    path: &Path,
+) -> Result<(PackageMetadata, Vec<Namespace>), LaibraryError> {
     let metadata = analyser.get_package_metadata(path)?;
     let file_paths = get_source_file_paths(
         path.to_string_lossy().into_owned(),
@@ -34,17 +60,135 @@ pub fn generate_documentation(language: &str, path: &Path) -> Result<String, Lai
     let mut parser = get_parser(&analyser.get_parser_language())?;
     let namespaces = analyser.extract_public_api(&file_paths, &metadata.name, &mut parser)?;
 
-    format_library_context(&metadata, &namespaces, language)
+    Ok((metadata, namespaces))
+}
+
+/// Document every member of a Cargo workspace and aggregate their namespaces
+/// into one list, each member's namespaces prefixed with its own crate name
+/// (each member keeping its own `PackageMetadata` resolved from its own
+/// manifest, so its `crate_name` - and therefore its namespace prefixes - stay
+/// correct). A member's crate-level namespace is present only when its crate
+/// root has public symbols of its own; otherwise its top-level modules are
+/// the top-level namespaces for that member. Only
+/// each member's namespaces make it into the aggregated output; its version
+/// and documentation are resolved but have no home in this aggregated
+/// namespace list, so they're intentionally not surfaced here.
+///
+/// The workspace's own manifest supplies the overall `PackageMetadata`. Cargo
+/// workspace roots are commonly a *virtual* manifest - a `[workspace]` table
+/// with no `[package]` of its own - so that case falls back to a synthesized
+/// workspace-level `PackageMetadata` instead of erroring.
+fn generate_workspace_documentation(
+    analyser: &dyn Analyser,
+    workspace_root: &Path,
+    members: &[PathBuf],
+) -> Result<(PackageMetadata, Vec<Namespace>), LaibraryError> {
+    let metadata = resolve_workspace_metadata(analyser, workspace_root)?;
+
+    let mut namespaces = Vec::new();
+    for member_path in members {
+        let (_, member_namespaces) = generate_package_documentation(analyser, member_path)?;
+        namespaces.extend(member_namespaces);
+    }
+
+    Ok((metadata, namespaces))
+}
+
+/// Resolve the workspace root's own `PackageMetadata`, falling back to a
+/// synthesized one when the manifest is virtual (see [`has_package_table`])
+/// rather than asking the analyser to parse a `[package]` table that isn't
+/// there.
+fn resolve_workspace_metadata(
+    analyser: &dyn Analyser,
+    workspace_root: &Path,
+) -> Result<PackageMetadata, LaibraryError> {
+    let manifest = std::fs::read_to_string(workspace_root.join("Cargo.toml")).unwrap_or_default();
+
+    if has_package_table(&manifest) {
+        analyser.get_package_metadata(workspace_root)
+    } else {
+        Ok(virtual_workspace_metadata(workspace_root, &manifest))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::path::PathBuf;
 
     #[test]
     fn test_unsupported_language() {
-        let result = generate_documentation("unsupported", &PathBuf::new());
+        let result = generate_documentation("unsupported", &PathBuf::new(), "xml");
         assert!(matches!(result, Err(LaibraryError::UnsupportedLanguage(_))));
     }
+
+    #[test]
+    fn test_unsupported_format() {
+        let result = generate_documentation("rust", &PathBuf::new(), "unsupported");
+        assert!(matches!(result, Err(LaibraryError::UnsupportedFormat(_))));
+    }
+
+    fn temp_workspace_dir(name: &str) -> PathBuf {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "laibrary-lib-{name}-test-{:?}",
+            std::thread::current().id()
+        ));
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        temp_dir
+    }
+
+    fn write_member(workspace_root: &Path, name: &str, version: &str) {
+        let member_dir = workspace_root.join("crates").join(name);
+        std::fs::create_dir_all(member_dir.join("src")).unwrap();
+        std::fs::write(
+            member_dir.join("Cargo.toml"),
+            format!("[package]\nname = \"{name}\"\nversion = \"{version}\"\n"),
+        )
+        .unwrap();
+        std::fs::write(
+            member_dir.join("src/lib.rs"),
+            format!("pub fn {name}_fn() {{}}\n"),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_workspace_members_are_aggregated_under_their_own_crate_name() {
+        let workspace_root = temp_workspace_dir("workspace");
+        std::fs::create_dir_all(&workspace_root).unwrap();
+        std::fs::write(
+            workspace_root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )
+        .unwrap();
+        write_member(&workspace_root, "alpha", "0.1.0");
+        write_member(&workspace_root, "beta", "0.2.0");
+
+        let documentation = generate_documentation("rust", &workspace_root, "json").unwrap();
+
+        assert!(documentation.contains("\"name\":\"alpha\""));
+        assert!(documentation.contains("\"name\":\"beta\""));
+        assert!(documentation.contains("\"alpha_fn\""));
+        assert!(documentation.contains("\"beta_fn\""));
+
+        std::fs::remove_dir_all(&workspace_root).unwrap();
+    }
+
+    #[test]
+    fn test_virtual_workspace_manifest_resolves_metadata_without_a_root_package() {
+        let workspace_root = temp_workspace_dir("virtual-workspace");
+        std::fs::create_dir_all(&workspace_root).unwrap();
+        std::fs::write(
+            workspace_root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.package]\nversion = \"1.2.3\"\n",
+        )
+        .unwrap();
+        write_member(&workspace_root, "alpha", "0.1.0");
+
+        let documentation = generate_documentation("rust", &workspace_root, "json").unwrap();
+
+        assert!(documentation.contains("\"version\":\"1.2.3\""));
+        assert!(documentation.contains("\"alpha_fn\""));
+
+        std::fs::remove_dir_all(&workspace_root).unwrap();
+    }
 }
@@ -0,0 +1,42 @@
+use std::path::PathBuf;
+
+/// Metadata about a documented package, typically sourced from its manifest.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackageMetadata {
+    pub name: String,
+    pub version: String,
+    pub documentation: String,
+}
+
+/// A single public symbol extracted from a source file, along with its source code.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symbol {
+    pub name: String,
+    pub source_code: String,
+}
+
+/// A source file handed to an `Analyser` for parsing.
+#[derive(Debug, Clone)]
+pub struct SourceFile {
+    pub path: PathBuf,
+    pub content: String,
+}
+
+/// A node in the namespace hierarchy extracted from a library's public API.
+///
+/// `children` holds the namespaces nested directly under this one, so a tree can be
+/// walked from any of its roots; the symbols and doc comment are this namespace's own.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Namespace {
+    pub name: String,
+    pub symbols: Vec<Symbol>,
+    pub missing_symbols: Vec<String>,
+    pub doc_comment: Option<String>,
+    pub children: Vec<Namespace>,
+}
+
+impl Namespace {
+    pub fn get_symbol(&self, name: &str) -> Option<&Symbol> {
+        self.symbols.iter().find(|symbol| symbol.name == name)
+    }
+}
@@ -0,0 +1,300 @@
+use crate::types::PackageMetadata;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// If `path` is the root of a Cargo workspace, the filesystem paths of its
+/// member crates; `None` if `path` has no `Cargo.toml`, or has one without a
+/// `[workspace]` table (an ordinary single-package manifest) or whose
+/// `[workspace]` table has no `members = [...]` array.
+///
+/// Member patterns are resolved relative to the workspace root. A glob like
+/// `crates/*` expands to every immediate subdirectory of `crates/` that has
+/// its own `Cargo.toml`; anything else is treated as a literal path. When the
+/// manifest has both a `[package]` and a `[workspace]` table, the root crate
+/// is listed first - Cargo treats that combination as the root package being
+/// an implicit workspace member, and its public API should be documented
+/// alongside the other members rather than silently dropped.
+pub fn find_workspace_members(path: &Path) -> Option<Vec<PathBuf>> {
+    let manifest = fs::read_to_string(path.join("Cargo.toml")).ok()?;
+    let members = parse_workspace_members(&manifest)?;
+
+    let mut resolved = Vec::new();
+    if has_package_table(&manifest) {
+        resolved.push(path.to_path_buf());
+    }
+    resolved.extend(
+        members
+            .iter()
+            .flat_map(|pattern| resolve_member_pattern(path, pattern)),
+    );
+
+    Some(resolved)
+}
+
+/// Extract the `members = [...]` array out of a manifest's `[workspace]`
+/// table. Returns `None` if the manifest has no `[workspace]` section, or that
+/// section has no `members = [...]` array.
+fn parse_workspace_members(manifest: &str) -> Option<Vec<String>> {
+    let workspace_start = manifest.find("[workspace]")?;
+    let section = &manifest[workspace_start..];
+    let section_end = section[1..].find("\n[").map_or(section.len(), |offset| offset + 1);
+    let section = &section[..section_end];
+
+    let members_start = section.find("members")?;
+    let list_start = section[members_start..].find('[')? + members_start;
+    let list_end = section[list_start..].find(']')? + list_start;
+    let list = &section[list_start + 1..list_end];
+
+    Some(
+        list.split(',')
+            .map(|entry| entry.trim().trim_matches('"').to_string())
+            .filter(|entry| !entry.is_empty())
+            .collect(),
+    )
+}
+
+fn resolve_member_pattern(workspace_root: &Path, pattern: &str) -> Vec<PathBuf> {
+    let Some(prefix) = pattern.strip_suffix("/*") else {
+        return vec![workspace_root.join(pattern)];
+    };
+
+    let Ok(entries) = fs::read_dir(workspace_root.join(prefix)) else {
+        return Vec::new();
+    };
+
+    // `read_dir`'s order is filesystem-dependent, so sort to keep the
+    // aggregated documentation's member order stable across runs.
+    let mut members: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.join("Cargo.toml").exists())
+        .collect();
+    members.sort();
+    members
+}
+
+/// Whether a manifest has a top-level `[package]` table. A Cargo workspace
+/// root commonly does not - a *virtual* manifest has only a `[workspace]`
+/// table and no package of its own.
+pub fn has_package_table(manifest: &str) -> bool {
+    manifest.lines().any(|line| line.trim() == "[package]")
+}
+
+/// Build `PackageMetadata` for a workspace root whose manifest is virtual
+/// (see [`has_package_table`]). A virtual manifest has no name, version, or
+/// crate-level documentation of its own, so this falls back to the
+/// workspace directory's name and, if present, the version declared in a
+/// `[workspace.package]` table, rather than erroring the way a
+/// single-package `Analyser::get_package_metadata` would.
+pub fn virtual_workspace_metadata(workspace_root: &Path, manifest: &str) -> PackageMetadata {
+    let name = workspace_root
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let version = parse_workspace_package_version(manifest).unwrap_or_default();
+
+    PackageMetadata {
+        name,
+        version,
+        documentation: String::new(),
+    }
+}
+
+/// Extract `version` out of a manifest's `[workspace.package]` table, if any.
+fn parse_workspace_package_version(manifest: &str) -> Option<String> {
+    let section_start = manifest.find("[workspace.package]")?;
+    let section = &manifest[section_start..];
+    let section_end = section[1..].find("\n[").map_or(section.len(), |offset| offset + 1);
+    let section = &section[..section_end];
+
+    let version_start = section.find("version")?;
+    let rest = &section[version_start..];
+    let quote_start = rest.find('"')? + 1;
+    let quote_end = rest[quote_start..].find('"')? + quote_start;
+    Some(rest[quote_start..quote_end].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_without_workspace_section_is_not_a_workspace() {
+        let manifest = r#"
+[package]
+name = "solo"
+version = "0.1.0"
+"#;
+        assert_eq!(parse_workspace_members(manifest), None);
+    }
+
+    #[test]
+    fn explicit_members_are_parsed() {
+        let manifest = r#"
+[workspace]
+members = ["crates/a", "crates/b"]
+"#;
+        assert_eq!(
+            parse_workspace_members(manifest),
+            Some(vec!["crates/a".to_string(), "crates/b".to_string()])
+        );
+    }
+
+    #[test]
+    fn members_dont_leak_into_a_later_table() {
+        let manifest = r#"
+[workspace]
+members = ["crates/a"]
+
+[workspace.dependencies]
+serde = "1"
+"#;
+        assert_eq!(
+            parse_workspace_members(manifest),
+            Some(vec!["crates/a".to_string()])
+        );
+    }
+
+    #[test]
+    fn glob_member_expands_to_subdirectories_with_a_manifest() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "laibrary-workspace-test-{:?}",
+            std::thread::current().id()
+        ));
+        let _ = fs::remove_dir_all(&temp_dir);
+        fs::create_dir_all(temp_dir.join("crates/a")).unwrap();
+        fs::create_dir_all(temp_dir.join("crates/b")).unwrap();
+        fs::write(temp_dir.join("crates/a/Cargo.toml"), "[package]\nname = \"a\"").unwrap();
+        // `b` has no Cargo.toml, so it isn't a crate and shouldn't be picked up.
+
+        let members = resolve_member_pattern(&temp_dir, "crates/*");
+
+        assert_eq!(members, vec![temp_dir.join("crates/a")]);
+
+        fs::remove_dir_all(&temp_dir).unwrap();
+    }
+
+    #[test]
+    fn glob_members_are_sorted_for_deterministic_output() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "laibrary-workspace-sort-test-{:?}",
+            std::thread::current().id()
+        ));
+        let _ = fs::remove_dir_all(&temp_dir);
+        for name in ["zeta", "alpha", "mu"] {
+            fs::create_dir_all(temp_dir.join("crates").join(name)).unwrap();
+            fs::write(
+                temp_dir.join("crates").join(name).join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\""),
+            )
+            .unwrap();
+        }
+
+        let members = resolve_member_pattern(&temp_dir, "crates/*");
+
+        assert_eq!(
+            members,
+            vec![
+                temp_dir.join("crates/alpha"),
+                temp_dir.join("crates/mu"),
+                temp_dir.join("crates/zeta"),
+            ]
+        );
+
+        fs::remove_dir_all(&temp_dir).unwrap();
+    }
+
+    #[test]
+    fn root_package_is_included_as_an_implicit_member() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "laibrary-workspace-implicit-root-test-{:?}",
+            std::thread::current().id()
+        ));
+        let _ = fs::remove_dir_all(&temp_dir);
+        fs::create_dir_all(temp_dir.join("crates/a")).unwrap();
+        fs::write(
+            temp_dir.join("Cargo.toml"),
+            "[package]\nname = \"root\"\nversion = \"0.1.0\"\n\n[workspace]\nmembers = [\"crates/a\"]\n",
+        )
+        .unwrap();
+        fs::write(temp_dir.join("crates/a/Cargo.toml"), "[package]\nname = \"a\"").unwrap();
+
+        let members = find_workspace_members(&temp_dir).unwrap();
+
+        assert_eq!(members, vec![temp_dir.clone(), temp_dir.join("crates/a")]);
+
+        fs::remove_dir_all(&temp_dir).unwrap();
+    }
+
+    #[test]
+    fn virtual_manifest_does_not_include_a_root_member() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "laibrary-workspace-virtual-root-test-{:?}",
+            std::thread::current().id()
+        ));
+        let _ = fs::remove_dir_all(&temp_dir);
+        fs::create_dir_all(temp_dir.join("crates/a")).unwrap();
+        fs::write(
+            temp_dir.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/a\"]\n",
+        )
+        .unwrap();
+        fs::write(temp_dir.join("crates/a/Cargo.toml"), "[package]\nname = \"a\"").unwrap();
+
+        let members = find_workspace_members(&temp_dir).unwrap();
+
+        assert_eq!(members, vec![temp_dir.join("crates/a")]);
+
+        fs::remove_dir_all(&temp_dir).unwrap();
+    }
+
+    #[test]
+    fn manifest_with_package_table_is_not_virtual() {
+        let manifest = r#"
+[package]
+name = "solo"
+version = "0.1.0"
+"#;
+        assert!(has_package_table(manifest));
+    }
+
+    #[test]
+    fn manifest_with_only_workspace_table_is_virtual() {
+        let manifest = r#"
+[workspace]
+members = ["crates/*"]
+"#;
+        assert!(!has_package_table(manifest));
+    }
+
+    #[test]
+    fn virtual_workspace_metadata_falls_back_to_directory_name() {
+        let workspace_root = Path::new("/tmp/my-workspace");
+        let manifest = r#"
+[workspace]
+members = ["crates/*"]
+"#;
+
+        let metadata = virtual_workspace_metadata(workspace_root, manifest);
+
+        assert_eq!(metadata.name, "my-workspace");
+        assert_eq!(metadata.version, "");
+        assert_eq!(metadata.documentation, "");
+    }
+
+    #[test]
+    fn virtual_workspace_metadata_picks_up_workspace_package_version() {
+        let workspace_root = Path::new("/tmp/my-workspace");
+        let manifest = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.package]
+version = "2.0.0"
+"#;
+
+        let metadata = virtual_workspace_metadata(workspace_root, manifest);
+
+        assert_eq!(metadata.version, "2.0.0");
+    }
+}